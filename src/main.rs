@@ -1,11 +1,138 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
+use std::fs::File;
+use std::io::BufReader;
 use std::time::{Duration, Instant};
 
+use chrono::{Local, Timelike};
 use eframe::{
     egui::{self, TextEdit},
     CreationContext,
 };
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+
+// Played on a loop when the countdown elapses. Missing file -> silent fallback.
+const ALARM_PATH: &str = "alarm.mp3";
+// Read once at startup; a default is written out when it is absent.
+const CONFIG_PATH: &str = "conf.ini";
+
+// A `hh:mm:ss` mask where an empty field is a wildcard matching any value, used
+// to fire recurring reminders at the given wall-clock marks.
+#[derive(Clone, Copy)]
+struct TimePattern {
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+}
+
+impl TimePattern {
+    // Parse a single `hh:mm:ss` entry; an empty field becomes a wildcard.
+    fn parse(entry: &str) -> Option<Self> {
+        let mut parts = entry.split(':');
+        let hour = Self::field(parts.next()?)?;
+        let minute = Self::field(parts.next()?)?;
+        let second = Self::field(parts.next()?)?;
+        Some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    // `Ok(None)` is a wildcard (empty field); a non-empty field must parse.
+    fn field(raw: &str) -> Option<Option<u32>> {
+        if raw.is_empty() {
+            Some(None)
+        } else {
+            raw.parse::<u32>().ok().map(Some)
+        }
+    }
+
+    // Does `(h, m, s)` satisfy this mask? Wildcard fields always match.
+    fn matches(&self, h: u32, m: u32, s: u32) -> bool {
+        self.hour.is_none_or(|v| v == h)
+            && self.minute.is_none_or(|v| v == m)
+            && self.second.is_none_or(|v| v == s)
+    }
+}
+
+// Startup configuration read from `conf.ini`.
+struct Config {
+    presets: Vec<u64>,
+    intervals: Vec<TimePattern>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            presets: vec![25 * 60, 5 * 60, 60 * 60],
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    // Load `conf.ini`, writing the default out when the file is missing.
+    fn load() -> Self {
+        match std::fs::read_to_string(CONFIG_PATH) {
+            Ok(contents) => Self::parse(&contents),
+            Err(_) => {
+                let config = Self::default();
+                let _ = std::fs::write(CONFIG_PATH, config.to_ini());
+                config
+            }
+        }
+    }
+
+    // Parse the `key=value` lines we understand, ignoring blanks/comments.
+    fn parse(contents: &str) -> Self {
+        let mut config = Self {
+            presets: Vec::new(),
+            intervals: Vec::new(),
+        };
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "presets" => {
+                    config.presets = value
+                        .split(',')
+                        .filter_map(|p| DateStr::from_colon_str(p.trim()))
+                        .map(|d| d.parse_secs().unwrap_or(0))
+                        .collect();
+                }
+                "intervals" => {
+                    config.intervals = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|e| !e.is_empty())
+                        .filter_map(TimePattern::parse)
+                        .collect();
+                }
+                _ => (),
+            }
+        }
+        config
+    }
+
+    fn to_ini(&self) -> String {
+        let presets = self
+            .presets
+            .iter()
+            .map(|s| {
+                let d = DateStr::from_seconds(*s);
+                format!("{}:{}:{}", d.hour, d.minute, d.second)
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("presets={presets}\nintervals=\n")
+    }
+}
 
 // 227x121
 const DEFAULT_WIDTH: f32 = 227.;
@@ -14,6 +141,7 @@ const DEFAULT_HEIGHT: f32 = 121.;
 fn main() -> Result<(), eframe::Error> {
     let options = eframe::NativeOptions {
         initial_window_size: Some(egui::vec2(DEFAULT_WIDTH, DEFAULT_HEIGHT)),
+        min_window_size: Some(egui::vec2(24.0, 24.0)),
         ..Default::default()
     };
 
@@ -53,6 +181,18 @@ impl DateStr {
             second,
         }
     }
+    // Parse a `hh:mm:ss` string (as written in `conf.ini`) into a `DateStr`.
+    fn from_colon_str(s: &str) -> Option<Self> {
+        let mut parts = s.split(':');
+        let hour = parts.next()?.to_string();
+        let minute = parts.next()?.to_string();
+        let second = parts.next()?.to_string();
+        Some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
     fn parse_secs(&self) -> Result<u64, std::num::ParseIntError> {
         let mut res = 0;
 
@@ -64,13 +204,138 @@ impl DateStr {
     }
 }
 
+// Whether the window runs as a countdown or a count-up stopwatch.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Countdown,
+    Stopwatch,
+}
+
+// The timer is either counting down toward a fixed `deadline` or holding a
+// captured `time_remaining` while paused. Keeping the two mutually exclusive
+// removes the per-frame `target` rewrite the old triple needed.
+enum State {
+    Running { deadline: Instant },
+    Paused { time_remaining: Duration },
+}
+
+impl State {
+    fn is_paused(&self) -> bool {
+        matches!(self, State::Paused { .. })
+    }
+
+    // Time left on the clock, derived from whichever variant is active.
+    fn remaining(&self) -> Duration {
+        match self {
+            State::Running { deadline } => deadline.saturating_duration_since(Instant::now()),
+            State::Paused { time_remaining } => *time_remaining,
+        }
+    }
+
+    // Resume a paused timer, pinning a fresh deadline; no-op when running.
+    fn start(&mut self) {
+        if let State::Paused { time_remaining } = self {
+            *self = State::Running {
+                deadline: Instant::now() + *time_remaining,
+            };
+        }
+    }
+
+    // Pause a running timer, capturing the exact time left; no-op when paused.
+    fn pause(&mut self) {
+        if let State::Running { deadline } = self {
+            *self = State::Paused {
+                time_remaining: deadline.saturating_duration_since(Instant::now()),
+            };
+        }
+    }
+
+    // Toggle between the two states.
+    fn start_pause(&mut self) {
+        match self {
+            State::Running { .. } => self.pause(),
+            State::Paused { .. } => self.start(),
+        }
+    }
+
+    // Count-up counterpart of `remaining`: in stopwatch mode `deadline` is
+    // reused as the (possibly back-dated) start instant and `time_remaining` as
+    // the elapsed time accumulated while paused.
+    fn elapsed(&self) -> Duration {
+        match self {
+            State::Running { deadline } => Instant::now().saturating_duration_since(*deadline),
+            State::Paused { time_remaining } => *time_remaining,
+        }
+    }
+
+    // Resume counting up, back-dating the start so `elapsed` continues from the
+    // accumulated value; no-op when running.
+    fn start_up(&mut self) {
+        if let State::Paused { time_remaining } = self {
+            let start = Instant::now()
+                .checked_sub(*time_remaining)
+                .unwrap_or_else(Instant::now);
+            *self = State::Running { deadline: start };
+        }
+    }
+
+    // Pause a count-up clock, capturing the elapsed time; no-op when paused.
+    fn pause_up(&mut self) {
+        if let State::Running { deadline } = self {
+            *self = State::Paused {
+                time_remaining: Instant::now().saturating_duration_since(*deadline),
+            };
+        }
+    }
+}
+
 struct App {
     input: DateStr,
-    marked: DateStr,
-    target: Instant,
-    paused: bool,
-    remaining: u64,
+    mode: Mode,
+    laps: Vec<Duration>,
+    state: State,
     timer_duration: Duration,
+    // Kept alive for the whole program: dropping the stream stops all playback.
+    _stream: Option<OutputStream>,
+    stream_handle: Option<OutputStreamHandle>,
+    sink: Option<Sink>,
+    finished: bool,
+    config: Config,
+    // Whether an interval pattern matched last frame, so reminders fire on the
+    // rising edge instead of every frame the mark is held.
+    interval_active: bool,
+    // Persisted window-behaviour preferences.
+    always_on_top: bool,
+    frameless: bool,
+    shrink_to_fit: bool,
+}
+
+impl App {
+    // Start looping the alarm. Silently does nothing if audio is unavailable or
+    // the sound file is missing.
+    fn play_alarm(&mut self) {
+        let Some(handle) = &self.stream_handle else {
+            return;
+        };
+        let file = match File::open(ALARM_PATH) {
+            Ok(file) => file,
+            Err(_) => return,
+        };
+        let source = match Decoder::new(BufReader::new(file)) {
+            Ok(source) => source,
+            Err(_) => return,
+        };
+        if let Ok(sink) = Sink::try_new(handle) {
+            sink.append(source.repeat_infinite());
+            self.sink = Some(sink);
+        }
+    }
+
+    // Stop the alarm (if any) and allow it to trigger again next time.
+    fn stop_alarm(&mut self) {
+        self.sink = None;
+        self.finished = false;
+    }
 }
 
 impl App {
@@ -80,20 +345,50 @@ impl App {
             Some(storage) => {
                 let remaining = storage.get_string("remaining");
                 let duration = storage.get_string("duration");
-                let marked = storage.get_string("marked");
-                match (duration, remaining, marked) {
-                    (Some(duration), Some(remaining), Some(marked)) => {
+                let variant = storage.get_string("state");
+                let mode = storage.get_string("mode");
+                let laps = storage.get_string("laps");
+                let always_on_top = storage.get_string("always_on_top").as_deref() == Some("true");
+                let frameless = storage.get_string("frameless").as_deref() == Some("true");
+                let shrink_to_fit = storage.get_string("shrink_to_fit").as_deref() == Some("true");
+                match (duration, remaining) {
+                    (Some(duration), Some(remaining)) => {
                         let remaining = remaining.parse::<u64>().unwrap();
                         let duration = duration.parse::<u64>().unwrap();
-                        let marked = marked.parse::<u64>().unwrap();
+                        let time_remaining = Duration::from_secs(remaining);
+                        let mode = match mode.as_deref() {
+                            Some("stopwatch") => Mode::Stopwatch,
+                            _ => Mode::Countdown,
+                        };
+                        // In stopwatch mode the persisted value is elapsed time,
+                        // so reconstruct the count-up anchor instead.
+                        let state = match (variant.as_deref(), mode) {
+                            (Some("running"), Mode::Countdown) => State::Running {
+                                deadline: Instant::now() + time_remaining,
+                            },
+                            (Some("running"), Mode::Stopwatch) => State::Running {
+                                deadline: Instant::now()
+                                    .checked_sub(time_remaining)
+                                    .unwrap_or_else(Instant::now),
+                            },
+                            _ => State::Paused { time_remaining },
+                        };
+                        let laps = laps
+                            .unwrap_or_default()
+                            .split(',')
+                            .filter(|s| !s.is_empty())
+                            .filter_map(|s| s.parse::<u64>().ok())
+                            .map(Duration::from_secs)
+                            .collect();
                         App {
-                            target: Instant::now()
-                                .checked_add(Duration::from_secs(duration))
-                                .unwrap(),
-                            remaining,
+                            state,
+                            mode,
+                            laps,
                             timer_duration: Duration::from_secs(duration),
                             input: DateStr::from_seconds(remaining),
-                            marked: DateStr::from_seconds(marked),
+                            always_on_top,
+                            frameless,
+                            shrink_to_fit,
                             ..Default::default()
                         }
                     }
@@ -106,22 +401,61 @@ impl App {
 
 impl Default for App {
     fn default() -> Self {
+        let (stream, stream_handle) = match OutputStream::try_default() {
+            Ok((stream, handle)) => (Some(stream), Some(handle)),
+            Err(_) => (None, None),
+        };
         Self {
             input: DateStr::default(),
-            marked: DateStr::default(),
-            target: Instant::now(),
-            paused: true,
-            remaining: 0,
+            mode: Mode::Countdown,
+            laps: Vec::new(),
+            state: State::Paused {
+                time_remaining: Duration::from_secs(0),
+            },
             timer_duration: Duration::from_secs(0),
+            _stream: stream,
+            stream_handle,
+            sink: None,
+            finished: false,
+            config: Config::load(),
+            interval_active: false,
+            always_on_top: false,
+            frameless: false,
+            shrink_to_fit: false,
         }
     }
 }
 
 impl eframe::App for App {
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
-        storage.set_string("remaining", self.remaining.to_string());
+        let variant = if self.state.is_paused() {
+            "paused"
+        } else {
+            "running"
+        };
+        // Persist elapsed time for the stopwatch, remaining time otherwise.
+        let value = match self.mode {
+            Mode::Countdown => self.state.remaining(),
+            Mode::Stopwatch => self.state.elapsed(),
+        };
+        let mode = match self.mode {
+            Mode::Countdown => "countdown",
+            Mode::Stopwatch => "stopwatch",
+        };
+        let laps = self
+            .laps
+            .iter()
+            .map(|d| d.as_secs().to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        storage.set_string("state", variant.to_string());
+        storage.set_string("mode", mode.to_string());
+        storage.set_string("remaining", value.as_secs().to_string());
         storage.set_string("duration", self.timer_duration.as_secs().to_string());
-        storage.set_string("marked", self.marked.parse_secs().unwrap().to_string());
+        storage.set_string("laps", laps);
+        storage.set_string("always_on_top", self.always_on_top.to_string());
+        storage.set_string("frameless", self.frameless.to_string());
+        storage.set_string("shrink_to_fit", self.shrink_to_fit.to_string());
         storage.flush();
     }
 
@@ -131,6 +465,31 @@ impl eframe::App for App {
             y: height,
         } = frame.info().window_info.size;
         ctx.request_repaint_after(Duration::from_secs(1));
+
+        // Apply the persisted window-behaviour preferences each frame.
+        frame.set_always_on_top(self.always_on_top);
+        frame.set_decorations(!self.frameless);
+        if self.shrink_to_fit {
+            frame.set_window_size(ctx.used_size());
+        }
+
+        // Recurring interval reminders: fire the completion path on the rising
+        // edge of any wildcard pattern matching the current wall-clock time.
+        if !self.config.intervals.is_empty() {
+            let now = Local::now();
+            let (h, m, s) = (now.hour(), now.minute(), now.second());
+            let matched = self
+                .config
+                .intervals
+                .iter()
+                .any(|pattern| pattern.matches(h, m, s));
+            if matched && !self.interval_active && !self.finished {
+                self.finished = true;
+                self.play_alarm();
+            }
+            self.interval_active = matched;
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
             let space_pressed = ctx.input(|k| k.key_pressed(egui::Key::Space));
 
@@ -138,8 +497,20 @@ impl eframe::App for App {
                 if width == 0. || height == 0. {
                     return;
                 }
+                // Normally the font scales with the live window size. But with
+                // "shrink to fit" on, the window size itself is set from this
+                // frame's rendered content (below), which was in turn laid out
+                // at a font size driven by last frame's window size -- feeding
+                // the live size back in here would turn that into a closed
+                // loop. Anchor the scale to the fixed default size instead so
+                // shrink-to-fit stays a one-shot "fit window to content."
+                let (scale_width, scale_height) = if self.shrink_to_fit {
+                    (DEFAULT_WIDTH, DEFAULT_HEIGHT)
+                } else {
+                    (width, height)
+                };
                 for (_, font_id) in ui.style_mut().text_styles.iter_mut() {
-                    font_id.size *= (width * height).sqrt()
+                    font_id.size *= (scale_width * scale_height).sqrt()
                         / ((DEFAULT_WIDTH * DEFAULT_HEIGHT) as f32).sqrt()
                         * 1.85;
 
@@ -148,83 +519,248 @@ impl eframe::App for App {
                 }
 
                 ui.add_space(height / 15.);
-                ui.columns(5, |columns| {
-                    let te = TextEdit::singleline(&mut self.input.hour)
-                        .char_limit(2)
-                        .horizontal_align(egui::Align::Center);
-                    columns[1].add_enabled(self.paused, te);
-
-                    let te = TextEdit::singleline(&mut self.input.minute)
-                        .char_limit(2)
-                        .horizontal_align(egui::Align::Center);
-                    columns[2].add_enabled(self.paused, te);
-
-                    let te = TextEdit::singleline(&mut self.input.second)
-                        .char_limit(2)
-                        .horizontal_align(egui::Align::Center);
-                    columns[3].add_enabled(self.paused, te);
+
+                // Mode can only be switched while the clock is stopped.
+                let paused = self.state.is_paused();
+                let previous_mode = self.mode;
+                ui.add_enabled_ui(paused, |ui| {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.selectable_value(&mut self.mode, Mode::Countdown, "Countdown");
+                        ui.selectable_value(&mut self.mode, Mode::Stopwatch, "Stopwatch");
+                    });
                 });
+                if self.mode != previous_mode {
+                    // Switching modes leaves a stale deadline/remainder behind
+                    // (e.g. a paused countdown's time left); zero it out so the
+                    // new mode starts from a clean slate. Clear the laps too,
+                    // since they're only meaningful relative to one continuous
+                    // Stopwatch run and this reset is the only way elapsed time
+                    // goes back to 0.
+                    self.state = State::Paused {
+                        time_remaining: Duration::ZERO,
+                    };
+                    self.laps.clear();
+                }
 
-                if self.paused {
-                    if ui.button("Start").clicked() || space_pressed {
-                        self.input.hour = format!("{:0>2}", self.input.hour);
-                        self.input.minute = format!("{:0>2}", self.input.minute);
-                        self.input.second = format!("{:0>2}", self.input.second);
-                        match self.input.parse_secs() {
-                            Ok(s) => {
-                                self.paused = false;
-                                self.target =
-                                    Instant::now().checked_add(Duration::from_secs(s)).unwrap();
-                                self.timer_duration = Duration::from_secs(s);
-                                self.remaining = self.timer_duration.as_secs();
+                match self.mode {
+                    // Countdown takes an editable duration in the input fields.
+                    Mode::Countdown => {
+                        ui.columns(5, |columns| {
+                            let te = TextEdit::singleline(&mut self.input.hour)
+                                .char_limit(2)
+                                .horizontal_align(egui::Align::Center);
+                            columns[1].add_enabled(paused, te);
+
+                            let te = TextEdit::singleline(&mut self.input.minute)
+                                .char_limit(2)
+                                .horizontal_align(egui::Align::Center);
+                            columns[2].add_enabled(paused, te);
+
+                            let te = TextEdit::singleline(&mut self.input.second)
+                                .char_limit(2)
+                                .horizontal_align(egui::Align::Center);
+                            columns[3].add_enabled(paused, te);
+                        });
+                    }
+                    // Stopwatch counts up from zero, so show elapsed as a label.
+                    Mode::Stopwatch => {
+                        let elapsed = DateStr::from_seconds(self.state.elapsed().as_secs());
+                        ui.label(format!(
+                            "{}:{}:{}",
+                            elapsed.hour, elapsed.minute, elapsed.second
+                        ));
+                    }
+                }
+
+                // Flash the window while the alarm is sounding.
+                if self.finished {
+                    let alpha = if Local::now().second().is_multiple_of(2) {
+                        120
+                    } else {
+                        0
+                    };
+                    let rect = ui.clip_rect();
+                    ui.painter().rect_filled(
+                        rect,
+                        0.0,
+                        egui::Color32::from_rgba_unmultiplied(220, 50, 50, alpha),
+                    );
+                }
+
+                // One button per configured preset; clicking fills the inputs.
+                if self.mode == Mode::Countdown
+                    && self.state.is_paused()
+                    && !self.config.presets.is_empty()
+                {
+                    let presets = self.config.presets.clone();
+                    ui.horizontal_wrapped(|ui| {
+                        for preset in &presets {
+                            let label = DateStr::from_seconds(*preset);
+                            if ui
+                                .button(format!(
+                                    "{}:{}:{}",
+                                    label.hour, label.minute, label.second
+                                ))
+                                .clicked()
+                            {
+                                self.input = label;
                             }
+                        }
+                    });
+                }
 
-                            Err(_) => (),
+                if self.state.is_paused() {
+                    if ui.button("Start").clicked() || space_pressed {
+                        self.stop_alarm();
+                        match self.mode {
+                            Mode::Countdown => {
+                                self.input.hour = format!("{:0>2}", self.input.hour);
+                                self.input.minute = format!("{:0>2}", self.input.minute);
+                                self.input.second = format!("{:0>2}", self.input.second);
+                                if let Ok(s) = self.input.parse_secs() {
+                                    self.timer_duration = Duration::from_secs(s);
+                                    self.state = State::Paused {
+                                        time_remaining: self.timer_duration,
+                                    };
+                                    // Always paused at this point, so this is
+                                    // the resuming half of the toggle.
+                                    self.state.start_pause();
+                                }
+                            }
+                            Mode::Stopwatch => self.state.start_up(),
                         }
                     }
                 } else {
                     if ui.button("Pause").clicked() || space_pressed {
-                        self.paused = true;
+                        match self.mode {
+                            // Always running at this point, so this is the
+                            // pausing half of the toggle.
+                            Mode::Countdown => self.state.start_pause(),
+                            Mode::Stopwatch => self.state.pause_up(),
+                        }
+                        self.stop_alarm();
                     }
                 }
 
-                let now = Instant::now();
+                // Countdown keeps the input fields mirroring the time left and
+                // fires the alarm when the deadline passes.
+                if self.mode == Mode::Countdown && !self.state.is_paused() {
+                    let remaining = self.state.remaining();
+                    if remaining > Duration::ZERO {
+                        let secs = remaining.as_secs();
+                        let h = secs / 3600;
+                        let m = (secs % 3600) / 60;
+                        let s = secs % 60;
+                        self.input.hour = format!("{h:0>2}");
+                        self.input.minute = format!("{m:0>2}");
+                        self.input.second = format!("{s:0>2}");
+                    } else {
+                        // The timer has elapsed. Latch to 00:00:00 and start the
+                        // alarm once, keeping it looping until Start/Pause is hit.
+                        self.input.hour = String::from("00");
+                        self.input.minute = String::from("00");
+                        self.input.second = String::from("00");
 
-                if self.paused {
-                    self.target = now
-                        .checked_add(Duration::from_secs(self.remaining))
-                        .unwrap();
+                        if !self.finished {
+                            self.finished = true;
+                            self.play_alarm();
+                        }
+                    }
                 }
 
-                // if ui.button("Restart").clicked() {
-                //     self.target = now.checked_add(self.timer_duration).unwrap();
-                // }
+                // Progress ring that drains as the countdown runs: a circle
+                // stroked from 12 o'clock clockwise, proportional to the
+                // fraction of time left, reddening as the deadline nears.
+                if self.mode == Mode::Countdown && self.timer_duration.as_secs() > 0 {
+                    let fraction = (self.state.remaining().as_secs() as f32
+                        / self.timer_duration.as_secs() as f32)
+                        .clamp(0.0, 1.0);
 
-                if now < self.target {
-                    self.remaining = (self.target - now).as_secs();
-                    let h = self.remaining / 3600;
-                    let m = (self.remaining % 3600) / 60;
-                    let s = self.remaining % 60;
+                    let diameter = (width * 0.4).clamp(24.0, height.max(24.0));
+                    let (rect, _) = ui.allocate_exact_size(
+                        egui::vec2(diameter, diameter),
+                        egui::Sense::hover(),
+                    );
+                    let center = rect.center();
+                    let stroke_width = diameter * 0.08;
+                    let radius = diameter / 2.0 - stroke_width;
+                    let painter = ui.painter();
 
-                    if !self.paused {
-                        self.input.hour = format!("{h:0>2}");
-                        self.input.minute = format!("{m:0>2}");
-                        self.input.second = format!("{s:0>2}");
+                    // Faint full ring underneath the live arc.
+                    painter.circle_stroke(
+                        center,
+                        radius,
+                        egui::Stroke::new(stroke_width, egui::Color32::from_gray(60)),
+                    );
+
+                    // Interpolate toward red over the final tenth, pulsing the
+                    // alpha each repaint for extra urgency.
+                    let (color, pulse) = if fraction < 0.1 {
+                        let t = 1.0 - fraction / 0.1;
+                        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t) as u8;
+                        let time = ui.input(|i| i.time) as f32;
+                        let alpha = (0.5 + 0.5 * (time * 6.0).sin()).clamp(0.0, 1.0);
+                        (
+                            egui::Color32::from_rgb(lerp(100, 220), lerp(170, 50), lerp(230, 50)),
+                            alpha,
+                        )
+                    } else {
+                        (egui::Color32::from_rgb(100, 170, 230), 1.0)
+                    };
+                    let color = color.linear_multiply(pulse);
+
+                    let start = -std::f32::consts::FRAC_PI_2;
+                    let sweep = fraction * std::f32::consts::TAU;
+                    let segments = 64;
+                    let points: Vec<egui::Pos2> = (0..=segments)
+                        .map(|i| {
+                            let angle = start + sweep * (i as f32 / segments as f32);
+                            center + radius * egui::vec2(angle.cos(), angle.sin())
+                        })
+                        .collect();
+                    painter.add(egui::Shape::line(points, egui::Stroke::new(stroke_width, color)));
+
+                    // Keep the pulse animating while the alarm approaches.
+                    if fraction < 0.1 {
+                        ui.ctx().request_repaint();
                     }
                 }
 
-                if ui
-                    .button(
-                        format!(
-                            "Mark: {}:{}:{}",
-                            self.marked.hour, self.marked.minute, self.marked.second
-                        )
-                        .as_str(),
-                    )
-                    .clicked()
-                {
-                    self.marked = self.input.clone();
+                // Record a lap/split at the current time. Laps only make sense
+                // for a monotonically increasing clock, so keep this (and the
+                // list below) to Stopwatch mode.
+                if self.mode == Mode::Stopwatch && ui.button("Mark").clicked() {
+                    self.laps.push(self.state.elapsed());
                 }
+
+                // Laps with their delta from the previous lap.
+                if self.mode == Mode::Stopwatch && !self.laps.is_empty() {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        let mut prev = Duration::ZERO;
+                        for (i, lap) in self.laps.iter().enumerate() {
+                            let split = DateStr::from_seconds(lap.as_secs());
+                            let delta = DateStr::from_seconds(
+                                lap.saturating_sub(prev).as_secs(),
+                            );
+                            ui.label(format!(
+                                "#{}  {}:{}:{}  (+{}:{}:{})",
+                                i + 1,
+                                split.hour,
+                                split.minute,
+                                split.second,
+                                delta.hour,
+                                delta.minute,
+                                delta.second
+                            ));
+                            prev = *lap;
+                        }
+                    });
+                }
+
+                // Window-behaviour toggles, persisted across restarts.
+                ui.checkbox(&mut self.always_on_top, "Always on top");
+                ui.checkbox(&mut self.frameless, "Frameless");
+                ui.checkbox(&mut self.shrink_to_fit, "Shrink to fit");
             });
         });
     }